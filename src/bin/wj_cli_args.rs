@@ -20,9 +20,13 @@ pub enum Commands {
         #[arg(value_name = "NAME")]
         name: String,
 
-        /// Project template (cli, web, lib, wasm)
+        /// Project template (cli, web, lib, wasm, platformer-2d)
         #[arg(short, long, default_value = "cli")]
         template: String,
+
+        /// Scaffold a game project (shorthand for `--template platformer-2d`)
+        #[arg(long, conflicts_with = "template")]
+        game: bool,
     },
 
     /// Build a Windjammer project
@@ -5,8 +5,13 @@ use anyhow::Result;
 
 pub fn run(cli: Cli) -> Result<()> {
     match cli.command {
-        Commands::New { name, template } => {
-            windjammer::cli::new::handle_new_command(&name, &template)
+        Commands::New {
+            name,
+            template,
+            game,
+        } => {
+            let template = if game { "platformer-2d" } else { &template };
+            windjammer::cli::new::handle_new_command(&name, template)
                 .map_err(|e| anyhow::anyhow!("{}", e))?;
         }
         Commands::Build {
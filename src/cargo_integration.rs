@@ -10,6 +10,7 @@ use anyhow::Result;
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
+use crate::cargo_toml::dependency_management;
 use crate::test_runner;
 use crate::{error_mapper, source_map, CompilationTarget};
 pub enum RustFileType {
@@ -383,6 +384,7 @@ pub fn create_cargo_toml_with_deps(
     // NOTE: Users should explicitly add windjammer-ui or other framework dependencies
     // to their Cargo.toml - the compiler no longer auto-adds filesystem paths
     let mut external_deps = Vec::new();
+    let game_framework = dependency_management::resolve_game_framework(source_dir);
     for crate_name in external_crates {
         // THE WINDJAMMER WAY: Filter out Rust keywords (crate, super, self)
         // These are language features, not external dependencies!
@@ -402,16 +404,13 @@ pub fn create_cargo_toml_with_deps(
             || crate_name == "windjammer-runtime"
         {
             // Try to find windjammer-game in the workspace
-            // First, check if WINDJAMMER_GAME_PATH env var is set (for development)
-            if let Ok(game_path) = std::env::var("WINDJAMMER_GAME_PATH") {
-                let game_path = PathBuf::from(game_path);
-                if game_path.exists() {
-                    external_deps.push(format!(
-                        "windjammer-game = {{ path = \"{}\" }}",
-                        test_runner::path_to_toml_string(&game_path)
-                    ));
-                    continue; // Skip the crates.io fallback
-                }
+            // First, use the project's `[game] framework_path` (written by `wj new --game`)
+            if let Some(dep) = game_framework
+                .as_ref()
+                .and_then(|f| f.dep_for(&crate_name, output_dir))
+            {
+                external_deps.push(dep);
+                continue; // Skip the crates.io fallback
             }
 
             // Second, try to find it relative to the compiler source
@@ -438,32 +437,11 @@ pub fn create_cargo_toml_with_deps(
             };
 
             if !final_path.as_os_str().is_empty() && final_path.exists() {
-                // Read the actual crate name from Cargo.toml at the path
-                let cargo_toml_path = final_path.join("Cargo.toml");
-                let crate_name_normalized = if cargo_toml_path.exists() {
-                    // Try to read the actual crate name from Cargo.toml
-                    if let Ok(content) = std::fs::read_to_string(&cargo_toml_path) {
-                        // Parse name = "..." line
-                        if let Some(line) = content.lines().find(|l| l.trim().starts_with("name")) {
-                            if let Some(name_part) = line.split('"').nth(1) {
-                                name_part.to_string()
-                            } else {
-                                // Fallback: guess based on crate_name
-                                if crate_name.contains("_core") || crate_name.contains("-core") {
-                                    "windjammer-game-core".to_string()
-                                } else if crate_name.contains("_app") || crate_name.contains("-app")
-                                {
-                                    "windjammer-app".to_string()
-                                } else if crate_name.contains("_runtime")
-                                    || crate_name.contains("-runtime")
-                                {
-                                    "windjammer-runtime".to_string()
-                                } else {
-                                    "windjammer-game".to_string()
-                                }
-                            }
-                        } else {
-                            // Fallback: guess based on crate_name
+                // Read the actual crate name from Cargo.toml at the path,
+                // falling back to a guess based on crate_name
+                let crate_name_normalized =
+                    dependency_management::read_package_name(&final_path.join("Cargo.toml"))
+                        .unwrap_or_else(|| {
                             if crate_name.contains("_core") || crate_name.contains("-core") {
                                 "windjammer-game-core".to_string()
                             } else if crate_name.contains("_app") || crate_name.contains("-app") {
@@ -475,32 +453,7 @@ pub fn create_cargo_toml_with_deps(
                             } else {
                                 "windjammer-game".to_string()
                             }
-                        }
-                    } else {
-                        // Fallback: guess based on crate_name
-                        if crate_name.contains("_core") || crate_name.contains("-core") {
-                            "windjammer-game-core".to_string()
-                        } else if crate_name.contains("_app") || crate_name.contains("-app") {
-                            "windjammer-app".to_string()
-                        } else if crate_name.contains("_runtime") || crate_name.contains("-runtime")
-                        {
-                            "windjammer-runtime".to_string()
-                        } else {
-                            "windjammer-game".to_string()
-                        }
-                    }
-                } else {
-                    // Fallback: guess based on crate_name
-                    if crate_name.contains("_core") || crate_name.contains("-core") {
-                        "windjammer-game-core".to_string()
-                    } else if crate_name.contains("_app") || crate_name.contains("-app") {
-                        "windjammer-app".to_string()
-                    } else if crate_name.contains("_runtime") || crate_name.contains("-runtime") {
-                        "windjammer-runtime".to_string()
-                    } else {
-                        "windjammer-game".to_string()
-                    }
-                };
+                        });
 
                 external_deps.push(format!(
                     "{} = {{ path = \"{}\" }}",
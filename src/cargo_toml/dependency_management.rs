@@ -244,9 +244,16 @@ pub(crate) fn detect_external_crate_deps(output_dir: &Path, source_dir: &Path) -
         }
     }
 
+    if external_crates.is_empty() {
+        return Vec::new();
+    }
+    let game_framework = resolve_game_framework(source_dir);
+
     let mut deps = Vec::new();
     for crate_name in &external_crates {
-        if let Some(dep_line) = resolve_crate_path(crate_name, source_dir, output_dir) {
+        if let Some(dep_line) =
+            resolve_crate_path(crate_name, source_dir, output_dir, game_framework.as_ref())
+        {
             deps.push(dep_line);
         }
     }
@@ -271,9 +278,58 @@ pub(crate) fn walk_rs_files(dir: &Path) -> Result<Vec<PathBuf>> {
     Ok(files)
 }
 
+/// The game framework crate declared by the project's `[game] framework_path`.
+pub(crate) struct GameFrameworkCrate {
+    dir: PathBuf,
+    /// Package name from the crate's Cargo.toml, with `-` replaced by `_`
+    crate_name: String,
+}
+
+impl GameFrameworkCrate {
+    /// Dependency line for `crate_name` if it names this framework crate's package.
+    ///
+    /// Other framework crates (e.g. `windjammer_app` when the path points at
+    /// windjammer-game-core) are not bound here, so one package never appears under two names.
+    pub(crate) fn dep_for(&self, crate_name: &str, output_dir: &Path) -> Option<String> {
+        if crate_name.replace('-', "_") != self.crate_name {
+            return None;
+        }
+        check_cargo_toml(&self.dir, &self.crate_name, output_dir)
+    }
+}
+
+/// Resolve the project's `[game] framework_path` once per build.
+///
+/// Returns `None` when no config declares a framework path. A config that fails to
+/// parse, or a declared path that is missing or has no Cargo.toml, is reported as a
+/// warning rather than silently falling back to the directory-walk heuristic.
+pub(crate) fn resolve_game_framework(source_dir: &Path) -> Option<GameFrameworkCrate> {
+    match crate::config::find_game_framework_path(source_dir) {
+        Ok(Some(dir)) => match read_package_name(&dir.join("Cargo.toml")) {
+            Some(package) => Some(GameFrameworkCrate {
+                dir,
+                crate_name: package.replace('-', "_"),
+            }),
+            None => {
+                eprintln!(
+                    "Warning: [game] framework_path {} has no Cargo.toml",
+                    dir.display()
+                );
+                None
+            }
+        },
+        Ok(None) => None,
+        Err(e) => {
+            eprintln!("Warning: {}", e);
+            None
+        }
+    }
+}
+
 /// Attempt to find the crate's path on disk for path-based dependency.
 ///
-/// Searches upward from `source_dir` (up to 5 levels) and checks each
+/// The crate declared by `[game] framework_path` is resolved from there first.
+/// Otherwise searches upward from `source_dir` (up to 5 levels) and checks each
 /// ancestor's subdirectories (up to 2 levels deep) for a matching
 /// `Cargo.toml`. Uses absolute paths so relative `source_dir` values
 /// (e.g. `"src"`) don't limit traversal depth. Skips any match that
@@ -282,7 +338,12 @@ pub(crate) fn resolve_crate_path(
     crate_name: &str,
     source_dir: &Path,
     output_dir: &Path,
+    game_framework: Option<&GameFrameworkCrate>,
 ) -> Option<String> {
+    if let Some(dep) = game_framework.and_then(|f| f.dep_for(crate_name, output_dir)) {
+        return Some(dep);
+    }
+
     let hyphenated = crate_name.replace('_', "-");
 
     let abs_source = source_dir.canonicalize().unwrap_or_else(|_| {
@@ -1,6 +1,19 @@
+use crate::cargo_toml::dependency_management::read_package_name;
 use std::fs;
 use std::path::Path;
 
+/// Templates that scaffold a game project (assets layout, editor tasks, framework path)
+const GAME_TEMPLATES: [&str; 1] = ["platformer-2d"];
+
+/// Default framework location, relative to the new project (sibling checkout)
+const DEFAULT_GAME_FRAMEWORK_PATH: &str = "../windjammer-game/windjammer-game-core";
+
+/// Framework package the game templates import (`use windjammer_game_core::prelude::*`)
+const GAME_FRAMEWORK_PACKAGE: &str = "windjammer-game-core";
+
+/// Asset directories created for game templates
+const GAME_ASSET_DIRS: [&str; 4] = ["textures", "audio", "fonts", "levels"];
+
 pub fn handle_new_command(name: &str, template: &str) -> Result<(), String> {
     // Validate project name
     if name.is_empty() {
@@ -12,7 +25,10 @@ pub fn handle_new_command(name: &str, template: &str) -> Result<(), String> {
     }
 
     // Validate template
-    let valid_templates = ["cli", "web", "lib", "wasm"];
+    let valid_templates: Vec<&str> = ["cli", "web", "lib", "wasm"]
+        .into_iter()
+        .chain(GAME_TEMPLATES)
+        .collect();
     if !valid_templates.contains(&template) {
         return Err(format!(
             "Invalid template '{}'. Valid templates: {}",
@@ -46,6 +62,11 @@ pub fn handle_new_command(name: &str, template: &str) -> Result<(), String> {
             println!("  wj build --target wasm");
             println!("  cd www && python3 -m http.server 8000");
         }
+        _ if GAME_TEMPLATES.contains(&template) => {
+            println!("  wj run src/main.wj");
+            println!();
+            println!("Game framework path is set in windjammer.toml ([game] framework_path).");
+        }
         _ => {}
     }
 
@@ -129,16 +150,25 @@ fn copy_template_files(
         println!("  ✓ Created src/{}", main_file);
     }
 
-    // Copy wj.toml with project name substitution
-    let toml_file = template_dir.join("wj.toml");
-    if toml_file.exists() {
-        let content =
-            fs::read_to_string(&toml_file).map_err(|e| format!("Failed to read wj.toml: {}", e))?;
-        let content = content.replace("{{PROJECT_NAME}}", project_name);
+    // Copy wj.toml / windjammer.toml with project name (and game framework path) substitution
+    let game_framework_path = GAME_TEMPLATES
+        .contains(&template)
+        .then(default_game_framework_path);
+    for config_name in ["wj.toml", "windjammer.toml"] {
+        let toml_file = template_dir.join(config_name);
+        if toml_file.exists() {
+            let content = fs::read_to_string(&toml_file)
+                .map_err(|e| format!("Failed to read {}: {}", config_name, e))?;
+            let mut content = content.replace("{{PROJECT_NAME}}", project_name);
+            if let Some(framework_path) = &game_framework_path {
+                content = content.replace("{{GAME_FRAMEWORK_PATH}}", framework_path);
+            }
 
-        let dest = project_path.join("wj.toml");
-        fs::write(&dest, content).map_err(|e| format!("Failed to write wj.toml: {}", e))?;
-        println!("  ✓ Created wj.toml");
+            let dest = project_path.join(config_name);
+            fs::write(&dest, content)
+                .map_err(|e| format!("Failed to write {}: {}", config_name, e))?;
+            println!("  ✓ Created {}", config_name);
+        }
     }
 
     // Copy .gitignore
@@ -185,9 +215,80 @@ fn copy_template_files(
         }
     }
 
+    // For game templates, create the assets layout and editor run configuration
+    if GAME_TEMPLATES.contains(&template) {
+        create_game_layout(template_dir, project_path)?;
+    }
+
     Ok(())
 }
 
+fn create_game_layout(template_dir: &Path, project_path: &Path) -> Result<(), String> {
+    let assets_dir = project_path.join("assets");
+    for sub_dir in GAME_ASSET_DIRS {
+        let dir = assets_dir.join(sub_dir);
+        fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create assets/{} directory: {}", sub_dir, e))?;
+        // Keep empty asset directories under version control
+        fs::write(dir.join(".gitkeep"), "")
+            .map_err(|e| format!("Failed to write assets/{}/.gitkeep: {}", sub_dir, e))?;
+    }
+    println!("  ✓ Created assets/ ({})", GAME_ASSET_DIRS.join(", "));
+
+    let tasks_file = template_dir.join("vscode").join("tasks.json");
+    if tasks_file.exists() {
+        let vscode_dir = project_path.join(".vscode");
+        fs::create_dir_all(&vscode_dir)
+            .map_err(|e| format!("Failed to create .vscode directory: {}", e))?;
+        fs::copy(&tasks_file, vscode_dir.join("tasks.json"))
+            .map_err(|e| format!("Failed to copy .vscode/tasks.json: {}", e))?;
+        println!("  ✓ Created .vscode/tasks.json");
+    }
+
+    Ok(())
+}
+
+/// Framework path written into a new game project's windjammer.toml.
+///
+/// Migrates an existing `WINDJAMMER_GAME_PATH` setup into the project config; otherwise
+/// assumes windjammer-game is checked out next to the new project. The env var is
+/// relative to the shell's directory, not the project, so it is written as an absolute path.
+///
+/// Game templates import windjammer-game-core, and `framework_path` only resolves imports
+/// of the package it points to. Legacy setups point the env var at the `windjammer-game`
+/// package instead, so its `windjammer-game-core` child or sibling is used when present;
+/// any other value is ignored with a warning naming the path written instead.
+fn default_game_framework_path() -> String {
+    let Ok(env_path) = std::env::var("WINDJAMMER_GAME_PATH") else {
+        return DEFAULT_GAME_FRAMEWORK_PATH.to_string();
+    };
+    let Ok(path) = Path::new(&env_path).canonicalize() else {
+        eprintln!(
+            "Warning: ignoring WINDJAMMER_GAME_PATH={}, which does not exist; \
+             writing framework_path = \"{}\"",
+            env_path, DEFAULT_GAME_FRAMEWORK_PATH
+        );
+        return DEFAULT_GAME_FRAMEWORK_PATH.to_string();
+    };
+    let mut candidates = vec![path.clone(), path.join(GAME_FRAMEWORK_PACKAGE)];
+    if let Some(parent) = path.parent() {
+        candidates.push(parent.join(GAME_FRAMEWORK_PACKAGE));
+    }
+    match candidates.iter().find(|dir| {
+        read_package_name(&dir.join("Cargo.toml")).as_deref() == Some(GAME_FRAMEWORK_PACKAGE)
+    }) {
+        Some(framework_dir) => crate::test_runner::path_to_toml_string(framework_dir),
+        None => {
+            eprintln!(
+                "Warning: ignoring WINDJAMMER_GAME_PATH={}, which has no {} crate at, \
+                 under, or next to it; writing framework_path = \"{}\"",
+                env_path, GAME_FRAMEWORK_PACKAGE, DEFAULT_GAME_FRAMEWORK_PATH
+            );
+            DEFAULT_GAME_FRAMEWORK_PATH.to_string()
+        }
+    }
+}
+
 fn init_git_repo(project_name: &str) -> Result<(), String> {
     use std::process::Command;
 
@@ -3,7 +3,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Dependency specification (matches Cargo.toml format)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +42,10 @@ pub struct WjConfig {
     /// Backend configuration for WASM proxy (optional)
     #[serde(default)]
     pub backend: Option<BackendConfig>,
+
+    /// Game project settings (optional, written by `wj new --game`)
+    #[serde(default)]
+    pub game: Option<GameConfig>,
 }
 
 /// Project metadata (for windjammer.toml)
@@ -75,6 +79,18 @@ pub struct SourcesConfig {
     pub roots: Vec<String>,
 }
 
+/// Game project configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GameConfig {
+    /// Path to a game framework crate directory, relative to the config file
+    /// e.g., "../windjammer-game/windjammer-game-core"
+    ///
+    /// Only imports of the package named in that crate's Cargo.toml resolve to it;
+    /// other framework crates fall back to the usual directory search.
+    #[serde(default, alias = "framework-path")]
+    pub framework_path: Option<String>,
+}
+
 /// Backend proxy configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendConfig {
@@ -220,6 +236,45 @@ impl WjConfig {
     }
 }
 
+/// Resolve the game framework crate path from the nearest `windjammer.toml` or `wj.toml`.
+///
+/// Searches upward from `start_dir` and stops at the first config file found
+/// (`windjammer.toml` before `wj.toml`); ancestor configs are never consulted. A
+/// `[game] framework_path` is resolved relative to the directory containing that file and
+/// canonicalized, since the generated Cargo.toml lives in a different directory. Returns
+/// `Ok(None)` when there is no config or it declares no framework path, and an error when
+/// the config fails to parse or the declared path does not exist.
+pub fn find_game_framework_path(start_dir: &Path) -> Result<Option<PathBuf>, String> {
+    let start_dir = start_dir
+        .canonicalize()
+        .unwrap_or_else(|_| start_dir.to_path_buf());
+    let mut dir = start_dir.as_path();
+    loop {
+        let nearest = ["windjammer.toml", "wj.toml"]
+            .iter()
+            .map(|file_name| dir.join(file_name))
+            .find(|candidate| candidate.exists());
+        if let Some(candidate) = nearest {
+            let config = WjConfig::load_from_file(&candidate)?;
+            let Some(framework_path) = config.game.and_then(|g| g.framework_path) else {
+                return Ok(None);
+            };
+            let resolved = dir.join(framework_path);
+            return resolved.canonicalize().map(Some).map_err(|_| {
+                format!(
+                    "[game] framework_path in {} points to {}, which does not exist",
+                    candidate.display(),
+                    resolved.display()
+                )
+            });
+        }
+        match dir.parent() {
+            Some(parent) if parent != dir => dir = parent,
+            _ => return Ok(None),
+        }
+    }
+}
+
 /// Windjammer project configuration (windjammer.toml) - for runtime settings
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct WindjammerConfig {
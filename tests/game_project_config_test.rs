@@ -0,0 +1,148 @@
+#![cfg(any(
+    not(any(
+        feature = "parser_tests",
+        feature = "analyzer_tests",
+        feature = "codegen_tests",
+        feature = "interpreter_tests",
+        feature = "conformance_tests",
+        feature = "integration_tests",
+    )),
+    feature = "integration_tests",
+))]
+
+// TDD Test: Game projects declare the framework path in windjammer.toml
+// Bug: Building a game required exporting WINDJAMMER_GAME_PATH before every build
+// Fix: `wj new --game` writes `[game] framework_path`, resolved relative to the config file
+// Impact: Game projects build from any shell without environment setup
+
+use std::fs;
+use tempfile::TempDir;
+use windjammer::config::{find_game_framework_path, WjConfig};
+
+#[test]
+fn test_game_section_parses() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("windjammer.toml");
+    fs::write(
+        &config_path,
+        r#"[project]
+name = "mygame"
+version = "0.1.0"
+
+[game]
+framework_path = "../windjammer-game/windjammer-game-core"
+"#,
+    )
+    .unwrap();
+
+    let config = WjConfig::load_from_file(&config_path).unwrap();
+    let game = config.game.expect("[game] section should parse");
+    assert_eq!(
+        game.framework_path.as_deref(),
+        Some("../windjammer-game/windjammer-game-core")
+    );
+}
+
+#[test]
+fn test_framework_path_resolves_relative_to_config() {
+    let temp_dir = TempDir::new().unwrap();
+    let framework_dir = temp_dir.path().join("windjammer-game/windjammer-game-core");
+    fs::create_dir_all(&framework_dir).unwrap();
+
+    let project_dir = temp_dir.path().join("mygame");
+    let src_dir = project_dir.join("src");
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        project_dir.join("windjammer.toml"),
+        "[game]\nframework_path = \"../windjammer-game/windjammer-game-core\"\n",
+    )
+    .unwrap();
+
+    // Lookup starts from the source directory and walks up to the config
+    let resolved = find_game_framework_path(&src_dir)
+        .unwrap()
+        .expect("framework path should resolve");
+    assert_eq!(resolved, framework_dir.canonicalize().unwrap());
+}
+
+#[test]
+fn test_missing_framework_path_reports_resolved_path() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("wj.toml"),
+        "[game]\nframework_path = \"does-not-exist\"\n",
+    )
+    .unwrap();
+
+    let err = find_game_framework_path(temp_dir.path()).unwrap_err();
+    let expected = temp_dir
+        .path()
+        .canonicalize()
+        .unwrap()
+        .join("does-not-exist");
+    assert!(
+        err.contains(&expected.display().to_string()),
+        "error should name the resolved path, got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_no_game_section_is_none() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("wj.toml"),
+        "[project]\nname = \"tool\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(find_game_framework_path(temp_dir.path()), Ok(None));
+}
+
+#[test]
+fn test_nearest_config_without_game_section_stops_search() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::create_dir_all(temp_dir.path().join("engine")).unwrap();
+    fs::write(
+        temp_dir.path().join("windjammer.toml"),
+        "[game]\nframework_path = \"engine\"\n",
+    )
+    .unwrap();
+
+    // A nested project's own config wins, even though an ancestor declares a framework
+    let project_dir = temp_dir.path().join("tools/mytool");
+    fs::create_dir_all(&project_dir).unwrap();
+    fs::write(
+        project_dir.join("wj.toml"),
+        "[package]\nname = \"mytool\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(find_game_framework_path(&project_dir), Ok(None));
+}
+
+#[test]
+fn test_malformed_config_reports_parse_error() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::create_dir_all(temp_dir.path().join("engine")).unwrap();
+    fs::write(
+        temp_dir.path().join("windjammer.toml"),
+        "[game]\nframework_path = \"engine\"\n",
+    )
+    .unwrap();
+
+    let project_dir = temp_dir.path().join("mygame");
+    fs::create_dir_all(&project_dir).unwrap();
+    fs::write(
+        project_dir.join("windjammer.toml"),
+        "[game\nframework_path = 1\n",
+    )
+    .unwrap();
+
+    let err = find_game_framework_path(&project_dir).unwrap_err();
+    assert!(
+        err.contains("Failed to parse") && err.contains("windjammer.toml"),
+        "expected a parse error for the project config, got: {}",
+        err
+    );
+}
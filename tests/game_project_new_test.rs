@@ -0,0 +1,322 @@
+#![cfg(any(
+    not(any(
+        feature = "parser_tests",
+        feature = "analyzer_tests",
+        feature = "codegen_tests",
+        feature = "interpreter_tests",
+        feature = "conformance_tests",
+        feature = "integration_tests",
+    )),
+    feature = "integration_tests",
+))]
+
+// TDD Test: `wj new --game` scaffolds a project wired to the framework path
+// Bug: The game template imported a removed `std::game` module and had no `main`,
+//      and `[game] framework_path` was only consulted by the legacy build path
+// Fix: Template imports `windjammer_game_core::prelude`, and `wj build` resolves
+//      the package at `framework_path` before the directory-walk heuristic
+// Impact: A fresh game project transpiles with the framework dependency wired in
+
+#[path = "common/test_utils.rs"]
+mod test_utils;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+fn wj_new(cwd: &Path, args: &[&str], game_path: Option<&str>) -> Output {
+    let mut cmd = Command::new(test_utils::wj_binary());
+    cmd.arg("new").args(args).current_dir(cwd);
+    match game_path {
+        Some(path) => cmd.env("WINDJAMMER_GAME_PATH", path),
+        None => cmd.env_remove("WINDJAMMER_GAME_PATH"),
+    };
+    cmd.output().expect("run wj new")
+}
+
+/// Write an empty crate named `package` at `dir`.
+fn write_stub_crate(dir: &Path, package: &str) {
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+            package
+        ),
+    )
+    .unwrap();
+}
+
+/// Absolute, forward-slash form of `dir` as written into generated TOML.
+fn toml_path(dir: &Path) -> String {
+    dir.canonicalize()
+        .unwrap()
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+#[test]
+fn test_new_game_creates_layout() {
+    let temp_dir = TempDir::new().unwrap();
+    let output = wj_new(temp_dir.path(), &["mygame", "--game"], None);
+    assert!(
+        output.status.success(),
+        "wj new --game failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let project = temp_dir.path().join("mygame");
+    for sub in ["textures", "audio", "fonts", "levels"] {
+        assert!(
+            project.join("assets").join(sub).join(".gitkeep").is_file(),
+            "missing assets/{}/.gitkeep",
+            sub
+        );
+    }
+
+    let tasks = fs::read_to_string(project.join(".vscode/tasks.json")).unwrap();
+    assert!(tasks.contains("wj run src/main.wj"));
+
+    let config = fs::read_to_string(project.join("windjammer.toml")).unwrap();
+    assert!(config.contains("name = \"mygame\""));
+    assert!(config.contains("framework_path = \"../windjammer-game/windjammer-game-core\""));
+    assert!(
+        !config.contains("{{"),
+        "unsubstituted placeholder:\n{}",
+        config
+    );
+}
+
+#[test]
+fn test_new_game_canonicalizes_relative_env_path() {
+    let temp_dir = TempDir::new().unwrap();
+    let framework_dir = temp_dir.path().join("engine");
+    write_stub_crate(&framework_dir, "windjammer-game-core");
+
+    let output = wj_new(temp_dir.path(), &["mygame", "--game"], Some("engine"));
+    assert!(
+        output.status.success(),
+        "wj new --game failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let config = fs::read_to_string(temp_dir.path().join("mygame/windjammer.toml")).unwrap();
+    let expected = toml_path(&framework_dir);
+    assert!(
+        config.contains(&format!("framework_path = \"{}\"", expected)),
+        "expected absolute framework path {}:\n{}",
+        expected,
+        config
+    );
+}
+
+#[test]
+fn test_new_game_migrates_legacy_env_path_to_core_sibling() {
+    let temp_dir = TempDir::new().unwrap();
+    // Legacy builds bound WINDJAMMER_GAME_PATH to the windjammer-game package
+    write_stub_crate(
+        &temp_dir.path().join("engine/windjammer-game"),
+        "windjammer-game",
+    );
+    let core_dir = temp_dir.path().join("engine/windjammer-game-core");
+    write_stub_crate(&core_dir, "windjammer-game-core");
+
+    let output = wj_new(
+        temp_dir.path(),
+        &["mygame", "--game"],
+        Some("engine/windjammer-game"),
+    );
+    assert!(output.status.success());
+    assert!(
+        output.stderr.is_empty(),
+        "unexpected warning: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let config = fs::read_to_string(temp_dir.path().join("mygame/windjammer.toml")).unwrap();
+    assert!(
+        config.contains(&format!("framework_path = \"{}\"", toml_path(&core_dir))),
+        "expected the windjammer-game-core sibling:\n{}",
+        config
+    );
+}
+
+#[test]
+fn test_new_game_ignores_env_path_to_other_package() {
+    let temp_dir = TempDir::new().unwrap();
+    write_stub_crate(&temp_dir.path().join("engine"), "windjammer-game");
+
+    let output = wj_new(temp_dir.path(), &["mygame", "--game"], Some("engine"));
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("WINDJAMMER_GAME_PATH=engine")
+            && stderr
+                .contains("writing framework_path = \"../windjammer-game/windjammer-game-core\""),
+        "expected a warning naming the ignored value and the written path:\n{}",
+        stderr
+    );
+    let config = fs::read_to_string(temp_dir.path().join("mygame/windjammer.toml")).unwrap();
+    assert!(config.contains("framework_path = \"../windjammer-game/windjammer-game-core\""));
+}
+
+#[test]
+fn test_new_non_game_template_ignores_env_path() {
+    let temp_dir = TempDir::new().unwrap();
+    let output = wj_new(
+        temp_dir.path(),
+        &["tool", "--template", "cli"],
+        Some("/nonexistent"),
+    );
+    assert!(output.status.success());
+    assert!(
+        output.stderr.is_empty(),
+        "non-game template should not read WINDJAMMER_GAME_PATH: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_new_game_warns_on_missing_env_path() {
+    let temp_dir = TempDir::new().unwrap();
+    let output = wj_new(
+        temp_dir.path(),
+        &["mygame", "--game"],
+        Some("no-such-engine"),
+    );
+    assert!(
+        output.status.success(),
+        "wj new --game failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("WINDJAMMER_GAME_PATH=no-such-engine"),
+        "expected a warning naming the ignored value:\n{}",
+        stderr
+    );
+    let config = fs::read_to_string(temp_dir.path().join("mygame/windjammer.toml")).unwrap();
+    assert!(config.contains("framework_path = \"../windjammer-game/windjammer-game-core\""));
+}
+
+#[test]
+fn test_new_game_conflicts_with_template() {
+    let temp_dir = TempDir::new().unwrap();
+    let output = wj_new(
+        temp_dir.path(),
+        &["mygame", "--game", "--template", "cli"],
+        None,
+    );
+    assert!(!output.status.success());
+    assert!(!temp_dir.path().join("mygame").exists());
+}
+
+/// Scaffold a game project whose framework_path points at a stub crate named `package`.
+fn new_game_with_stub_framework(temp_dir: &TempDir, package: &str) -> PathBuf {
+    let output = wj_new(temp_dir.path(), &["mygame", "--game"], None);
+    assert!(output.status.success());
+
+    // Stub checkout outside the default sibling layout
+    let framework_dir = temp_dir.path().join("engines/my-engine");
+    write_stub_crate(&framework_dir, package);
+
+    let project = temp_dir.path().join("mygame");
+    let config_path = project.join("windjammer.toml");
+    let config = fs::read_to_string(&config_path).unwrap().replace(
+        "../windjammer-game/windjammer-game-core",
+        "../engines/my-engine",
+    );
+    fs::write(&config_path, config).unwrap();
+    framework_dir
+}
+
+fn build_game(project: &Path) -> String {
+    let build_dir = project.join("build");
+    let output = test_utils::run_wj_command([
+        "build".as_ref(),
+        project.join("src/main.wj").as_os_str(),
+        "--output".as_ref(),
+        build_dir.as_os_str(),
+        "--no-cargo".as_ref(),
+    ]);
+    assert!(
+        output.status.success(),
+        "wj build failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let main_rs = fs::read_to_string(build_dir.join("main.rs")).unwrap();
+    assert!(main_rs.contains("use windjammer_game_core::prelude::*;"));
+    assert!(main_rs.contains("fn main()"));
+    fs::read_to_string(build_dir.join("Cargo.toml")).unwrap()
+}
+
+#[test]
+fn test_game_template_build_uses_framework_path() {
+    let temp_dir = TempDir::new().unwrap();
+    let framework_dir = new_game_with_stub_framework(&temp_dir, "windjammer-game-core");
+
+    let cargo_toml = build_game(&temp_dir.path().join("mygame"));
+    let framework_path = toml_path(&framework_dir);
+    assert!(
+        cargo_toml.contains(&format!(
+            "windjammer-game-core = {{ path = \"{}\" }}",
+            framework_path
+        )),
+        "framework dependency missing from Cargo.toml:\n{}",
+        cargo_toml
+    );
+}
+
+#[test]
+fn test_framework_path_only_binds_its_own_package() {
+    let temp_dir = TempDir::new().unwrap();
+    let framework_dir = new_game_with_stub_framework(&temp_dir, "windjammer-app");
+
+    // The template imports windjammer_game_core, which is not the package at framework_path
+    let cargo_toml = build_game(&temp_dir.path().join("mygame"));
+    let framework_path = toml_path(&framework_dir);
+    assert!(
+        !cargo_toml.contains(&framework_path),
+        "windjammer_game_core must not be bound to another package:\n{}",
+        cargo_toml
+    );
+}
+
+#[test]
+fn test_legacy_build_ignores_env_path() {
+    let temp_dir = TempDir::new().unwrap();
+    // framework_path does not provide windjammer_game_core, so nothing should bind it
+    new_game_with_stub_framework(&temp_dir, "windjammer-app");
+    let legacy_dir = temp_dir.path().join("legacy/windjammer-game");
+    write_stub_crate(&legacy_dir, "windjammer-game");
+
+    // `windjammer build` generates Cargo.toml through cargo_integration
+    let project = temp_dir.path().join("mygame");
+    let build_dir = project.join("build");
+    let output = Command::new(env!("CARGO_BIN_EXE_windjammer"))
+        .arg("build")
+        .arg("--path")
+        .arg(project.join("src/main.wj"))
+        .arg("--output")
+        .arg(&build_dir)
+        .args(["--target", "rust"])
+        .env("WINDJAMMER_GAME_PATH", &legacy_dir)
+        .output()
+        .expect("run windjammer build");
+    assert!(
+        output.status.success(),
+        "windjammer build failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let cargo_toml = fs::read_to_string(build_dir.join("Cargo.toml")).unwrap();
+    assert!(
+        !cargo_toml.contains(&toml_path(&legacy_dir)),
+        "WINDJAMMER_GAME_PATH must not be bound during builds:\n{}",
+        cargo_toml
+    );
+}